@@ -2,10 +2,13 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::env;
 use std::fs;
-use std::io::{BufRead, BufReader, Write};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
 use std::os::unix::net::UnixStream;
 use std::path::PathBuf;
-use std::process::{exit, Command, Stdio};
+use std::process::{exit, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
@@ -24,16 +27,95 @@ struct Response {
     error: Option<String>,
 }
 
+/// Whether an animated spinner should be drawn: never in `--json` mode or when
+/// stdout is not a TTY, so machine-readable output and piped logs stay clean.
+fn spinner_enabled(json_mode: bool) -> bool {
+    if json_mode {
+        return false;
+    }
+    unsafe { libc::isatty(libc::STDOUT_FILENO) == 1 }
+}
+
+/// A single-line animated spinner that resolves to a green ✓ or red ✗, matching
+/// the ANSI styling used by `print_response`. When disabled it is a no-op.
+struct Spinner {
+    done: Arc<AtomicBool>,
+    handle: Option<thread::JoinHandle<()>>,
+    message: String,
+    enabled: bool,
+}
+
+impl Spinner {
+    fn start(message: &str, enabled: bool) -> Spinner {
+        let done = Arc::new(AtomicBool::new(false));
+        let handle = if enabled {
+            let done = done.clone();
+            let message = message.to_string();
+            Some(thread::spawn(move || {
+                let frames = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+                let mut i = 0;
+                while !done.load(Ordering::Relaxed) {
+                    print!("\r\x1b[2K\x1b[36m{}\x1b[0m {}…", frames[i % frames.len()], message);
+                    io::stdout().flush().ok();
+                    i += 1;
+                    thread::sleep(Duration::from_millis(80));
+                }
+            }))
+        } else {
+            None
+        };
+        Spinner { done, handle, message: message.to_string(), enabled }
+    }
+
+    fn finish(mut self, success: bool) {
+        self.done.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+        if self.enabled {
+            let mark = if success {
+                "\x1b[32m✓\x1b[0m"
+            } else {
+                "\x1b[31m✗\x1b[0m"
+            };
+            println!("\r\x1b[2K{} {}", mark, self.message);
+        }
+    }
+}
+
+/// Run a `Command` to completion, drawing a spinner while it works when
+/// `show_spinner` is set (child output is suppressed so it cannot corrupt the
+/// spinner line).
+fn run_with_spinner(
+    message: &str,
+    show_spinner: bool,
+    command: &mut Command,
+) -> io::Result<ExitStatus> {
+    if show_spinner {
+        command.stdout(Stdio::null()).stderr(Stdio::null());
+        let spinner = Spinner::start(message, true);
+        let status = command.status();
+        spinner.finish(status.as_ref().map(|s| s.success()).unwrap_or(false));
+        status
+    } else {
+        command.status()
+    }
+}
+
+fn get_engine() -> String {
+    env::var("AGENT_BROWSER_ENGINE").unwrap_or_else(|_| "chromium".to_string())
+}
+
 fn get_socket_path() -> PathBuf {
     let session = env::var("AGENT_BROWSER_SESSION").unwrap_or_else(|_| "default".to_string());
     let tmp = env::temp_dir();
-    tmp.join(format!("agent-browser-{}.sock", session))
+    tmp.join(format!("agent-browser-{}-{}.sock", session, get_engine()))
 }
 
 fn get_pid_path() -> PathBuf {
     let session = env::var("AGENT_BROWSER_SESSION").unwrap_or_else(|_| "default".to_string());
     let tmp = env::temp_dir();
-    tmp.join(format!("agent-browser-{}.pid", session))
+    tmp.join(format!("agent-browser-{}-{}.pid", session, get_engine()))
 }
 
 fn is_daemon_running() -> bool {
@@ -52,7 +134,7 @@ fn is_daemon_running() -> bool {
     false
 }
 
-fn ensure_daemon() -> Result<(), String> {
+fn ensure_daemon(show_spinner: bool) -> Result<(), String> {
     let socket_path = get_socket_path();
     
     if is_daemon_running() && socket_path.exists() {
@@ -76,35 +158,167 @@ fn ensure_daemon() -> Result<(), String> {
     
     // Start daemon
     let session = env::var("AGENT_BROWSER_SESSION").unwrap_or_else(|_| "default".to_string());
-    Command::new("node")
+    let mut command = Command::new("node");
+    command
         .arg(daemon_path)
         .env("AGENT_BROWSER_DAEMON", "1")
         .env("AGENT_BROWSER_SESSION", &session)
+        .env("AGENT_BROWSER_ENGINE", get_engine())
         .stdin(Stdio::null())
         .stdout(Stdio::null())
-        .stderr(Stdio::null())
+        .stderr(Stdio::null());
+
+    // Strip bundle-local entries from the inherited environment when running
+    // inside an AppImage/Flatpak/Snap, otherwise the spawned node daemon
+    // resolves the wrong shared libraries and Chromium fails to launch.
+    sanitize_sandbox_env(&mut command);
+
+    command
         .spawn()
         .map_err(|e| format!("Failed to start daemon: {}", e))?;
     
     // Wait for socket
+    let spinner = Spinner::start("Starting browser daemon", show_spinner);
     for _ in 0..50 {
         if socket_path.exists() {
+            spinner.finish(true);
             return Ok(());
         }
         thread::sleep(Duration::from_millis(100));
     }
-    
+
+    spinner.finish(false);
     Err("Daemon failed to start".to_string())
 }
 
+/// A daemon connection over either a local Unix socket or a remote TCP socket.
+/// The newline-delimited JSON wire protocol is identical over both transports.
+enum Connection {
+    Unix(UnixStream),
+    Tcp(TcpStream),
+}
+
+impl Connection {
+    fn set_timeouts(&self) {
+        match self {
+            Connection::Unix(s) => {
+                s.set_read_timeout(Some(Duration::from_secs(30))).ok();
+                s.set_write_timeout(Some(Duration::from_secs(5))).ok();
+            }
+            Connection::Tcp(s) => {
+                s.set_read_timeout(Some(Duration::from_secs(30))).ok();
+                s.set_write_timeout(Some(Duration::from_secs(5))).ok();
+            }
+        }
+    }
+}
+
+impl Read for Connection {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Connection::Unix(s) => s.read(buf),
+            Connection::Tcp(s) => s.read(buf),
+        }
+    }
+}
+
+impl Write for Connection {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Connection::Unix(s) => s.write(buf),
+            Connection::Tcp(s) => s.write(buf),
+        }
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Connection::Unix(s) => s.flush(),
+            Connection::Tcp(s) => s.flush(),
+        }
+    }
+}
+
+fn connect() -> Result<Connection, String> {
+    if let Ok(remote) = env::var("AGENT_BROWSER_REMOTE") {
+        let stream = TcpStream::connect(&remote)
+            .map_err(|e| format!("Failed to connect to {}: {}", remote, e))?;
+        Ok(Connection::Tcp(stream))
+    } else {
+        let socket_path = get_socket_path();
+        let stream = UnixStream::connect(&socket_path)
+            .map_err(|e| format!("Failed to connect: {}", e))?;
+        Ok(Connection::Unix(stream))
+    }
+}
+
+/// Collect the sandbox bundle roots to strip from PATH-like variables, or an
+/// empty vec when not running inside a recognized sandbox.
+fn sandbox_roots() -> Vec<String> {
+    let mut roots = Vec::new();
+    if let Ok(appdir) = env::var("APPDIR") {
+        roots.push(appdir);
+    }
+    if PathBuf::from("/.flatpak-info").exists() {
+        // Flatpak mounts the app bundle at a fixed prefix.
+        roots.push("/app".to_string());
+    }
+    if let Ok(snap) = env::var("SNAP") {
+        roots.push(snap);
+    }
+    roots
+}
+
+/// Remove bundle-local entries from the colon-separated PATH-like variables the
+/// spawned daemon inherits. Entries under a bundle root are dropped, the
+/// remainder deduped while preserving order; a variable that ends up empty is
+/// unset outright (an empty value is not the same as an unset one to loaders).
+fn sanitize_sandbox_env(command: &mut Command) {
+    let roots = sandbox_roots();
+    if roots.is_empty() {
+        return;
+    }
+
+    const PATH_LIKE: [&str; 5] = [
+        "LD_LIBRARY_PATH",
+        "GST_PLUGIN_SYSTEM_PATH",
+        "GTK_PATH",
+        "XDG_DATA_DIRS",
+        "PYTHONPATH",
+    ];
+
+    for var in PATH_LIKE {
+        let value = match env::var(var) {
+            Ok(v) => v,
+            Err(_) => continue,
+        };
+
+        let mut cleaned: Vec<&str> = Vec::new();
+        for entry in value.split(':') {
+            if entry.is_empty() {
+                continue;
+            }
+            if roots
+                .iter()
+                .any(|root| entry == root || entry.starts_with(&format!("{}/", root)))
+            {
+                continue;
+            }
+            if !cleaned.contains(&entry) {
+                cleaned.push(entry);
+            }
+        }
+
+        if cleaned.is_empty() {
+            command.env_remove(var);
+        } else {
+            command.env(var, cleaned.join(":"));
+        }
+    }
+}
+
 fn send_command(cmd: Value) -> Result<Response, String> {
-    let socket_path = get_socket_path();
-    let mut stream = UnixStream::connect(&socket_path)
-        .map_err(|e| format!("Failed to connect: {}", e))?;
-    
-    stream.set_read_timeout(Some(Duration::from_secs(30))).ok();
-    stream.set_write_timeout(Some(Duration::from_secs(5))).ok();
-    
+    let mut stream = connect()?;
+    stream.set_timeouts();
+
     let mut json_str = serde_json::to_string(&cmd).map_err(|e| e.to_string())?;
     json_str.push('\n');
     
@@ -247,32 +461,58 @@ fn print_response(resp: &Response, json_mode: bool) {
     }
 }
 
-fn print_help() {
-    println!(r#"
-agent-browser - fast browser automation CLI (Rust)
-
-Usage: agent-browser <command> [args] [--json]
-
-Commands:
-  open <url>              Navigate to URL
-  click <sel>             Click element (@ref from snapshot)
-  fill <sel> <text>       Fill input
-  type <sel> <text>       Type text
-  hover <sel>             Hover element
-  snapshot [opts]         Get accessibility tree with refs
-  screenshot [path]       Take screenshot
-  get text <sel>          Get text content
-  get url                 Get current URL
-  get title               Get page title
-  press <key>             Press keyboard key
-  wait <ms|sel>           Wait for time or element
-  eval <js>               Evaluate JavaScript
-  close                   Close browser
-
-Setup:
-  install                 Install browser binaries
-  install --with-deps     Also install system dependencies (Linux)
+/// A top-level command, as listed in the help text and shell completions.
+/// Parsing does not read this table: `parse_command` dispatches with its own
+/// `match` on the command name.
+struct CommandSpec {
+    name: &'static str,
+    usage: &'static str,
+    help: &'static str,
+}
+
+/// Canonical command table. Keep this in sync with `parse_command`; help text
+/// and completion scripts are generated from it.
+const COMMANDS: &[CommandSpec] = &[
+    CommandSpec { name: "open", usage: "open <url>", help: "Navigate to URL" },
+    CommandSpec { name: "click", usage: "click <sel>", help: "Click element (@ref from snapshot)" },
+    CommandSpec { name: "fill", usage: "fill <sel> <text>", help: "Fill input" },
+    CommandSpec { name: "type", usage: "type <sel> <text>", help: "Type text" },
+    CommandSpec { name: "hover", usage: "hover <sel>", help: "Hover element" },
+    CommandSpec { name: "snapshot", usage: "snapshot [opts]", help: "Get accessibility tree with refs" },
+    CommandSpec { name: "screenshot", usage: "screenshot [path]", help: "Take screenshot" },
+    CommandSpec { name: "get", usage: "get <text|url|title>", help: "Get text content, URL, or title" },
+    CommandSpec { name: "press", usage: "press <key>", help: "Press keyboard key" },
+    CommandSpec { name: "wait", usage: "wait <ms|sel>", help: "Wait for time or element" },
+    CommandSpec { name: "back", usage: "back", help: "Go back in history" },
+    CommandSpec { name: "forward", usage: "forward", help: "Go forward in history" },
+    CommandSpec { name: "reload", usage: "reload", help: "Reload the current page" },
+    CommandSpec { name: "eval", usage: "eval <js>", help: "Evaluate JavaScript" },
+    CommandSpec { name: "close", usage: "close", help: "Close browser" },
+    CommandSpec { name: "install", usage: "install [--with-deps]", help: "Install browser binaries" },
+    CommandSpec { name: "completions", usage: "completions <bash|zsh|fish>", help: "Print a shell completion script" },
+];
+
+/// `get` subcommands offered by the shell completions. `parse_command` matches
+/// these names itself, and the help text shows them through the `get` usage.
+const GET_SUBCOMMANDS: &[&str] = &["text", "url", "title"];
+
+/// `snapshot` option flags.
+const SNAPSHOT_FLAGS: &[&str] = &[
+    "-i", "--interactive", "-c", "--compact", "-d", "--depth", "-s", "--selector",
+];
 
+/// Global flags accepted anywhere on the command line.
+const GLOBAL_FLAGS: &[&str] = &["--json", "--help", "--browser", "--remote"];
+
+fn print_help() {
+    println!("\nagent-browser - fast browser automation CLI (Rust)\n");
+    println!("Usage: agent-browser <command> [args] [--json]\n");
+    println!("Commands:");
+    for c in COMMANDS {
+        println!("  {:<23} {}", c.usage, c.help);
+    }
+    println!(
+        r#"
 Snapshot Options:
   -i, --interactive       Only interactive elements
   -c, --compact           Remove empty structural elements
@@ -281,61 +521,108 @@ Snapshot Options:
 
 Options:
   --json                  Output JSON
+  --browser <engine>      Browser engine: chromium (default), firefox, webkit
+  --remote <host:port>    Drive a remote daemon over TCP (skips local startup)
 
 Examples:
   agent-browser open example.com
   agent-browser snapshot -i
-  agent-browser click @e2
-"#);
+  agent-browser click @e2"#
+    );
 }
 
-fn run_install(with_deps: bool) {
+fn run_completions(shell: &str) {
+    let names: Vec<&str> = COMMANDS.iter().map(|c| c.name).collect();
+    match shell {
+        "bash" => {
+            println!("# bash completion for agent-browser");
+            println!("_agent_browser() {{");
+            println!("    local cur prev");
+            println!("    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"");
+            println!("    prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"");
+            println!("    local commands=\"{}\"", names.join(" "));
+            println!("    local global_flags=\"{}\"", GLOBAL_FLAGS.join(" "));
+            println!("    case \"$prev\" in");
+            println!("        get) COMPREPLY=( $(compgen -W \"{}\" -- \"$cur\") ); return ;;", GET_SUBCOMMANDS.join(" "));
+            println!("        completions) COMPREPLY=( $(compgen -W \"bash zsh fish\" -- \"$cur\") ); return ;;");
+            println!("        snapshot) COMPREPLY=( $(compgen -W \"{}\" -- \"$cur\") ); return ;;", SNAPSHOT_FLAGS.join(" "));
+            println!("    esac");
+            println!("    if [ \"$COMP_CWORD\" -eq 1 ]; then");
+            println!("        COMPREPLY=( $(compgen -W \"$commands\" -- \"$cur\") )");
+            println!("    else");
+            println!("        COMPREPLY=( $(compgen -W \"$global_flags\" -- \"$cur\") )");
+            println!("    fi");
+            println!("}}");
+            println!("complete -F _agent_browser agent-browser");
+        }
+        "zsh" => {
+            println!("#compdef agent-browser");
+            println!("_agent_browser() {{");
+            println!("    local -a commands");
+            println!("    commands=(");
+            for c in COMMANDS {
+                println!("        '{}:{}'", c.name, c.help);
+            }
+            println!("    )");
+            println!("    if (( CURRENT == 2 )); then");
+            println!("        _describe 'command' commands");
+            println!("        return");
+            println!("    fi");
+            println!("    case \"${{words[2]}}\" in");
+            println!("        get) _values 'subcommand' {} ;;", GET_SUBCOMMANDS.join(" "));
+            println!("        completions) _values 'shell' bash zsh fish ;;");
+            println!("        snapshot) _values 'flag' {} ;;", SNAPSHOT_FLAGS.join(" "));
+            println!("        *) _values 'flag' {} ;;", GLOBAL_FLAGS.join(" "));
+            println!("    esac");
+            println!("}}");
+            println!("_agent_browser \"$@\"");
+        }
+        "fish" => {
+            println!("# fish completion for agent-browser");
+            println!("complete -c agent-browser -f");
+            for c in COMMANDS {
+                println!(
+                    "complete -c agent-browser -n '__fish_use_subcommand' -a '{}' -d '{}'",
+                    c.name, c.help
+                );
+            }
+            println!(
+                "complete -c agent-browser -n '__fish_seen_subcommand_from get' -a '{}'",
+                GET_SUBCOMMANDS.join(" ")
+            );
+            println!("complete -c agent-browser -n '__fish_seen_subcommand_from completions' -a 'bash zsh fish'");
+            for flag in SNAPSHOT_FLAGS {
+                println!(
+                    "complete -c agent-browser -n '__fish_seen_subcommand_from snapshot' -a '{}'",
+                    flag
+                );
+            }
+            for flag in GLOBAL_FLAGS {
+                println!("complete -c agent-browser -a '{}'", flag);
+            }
+        }
+        other => {
+            eprintln!("\x1b[31m✗\x1b[0m Unknown shell: {} (expected bash, zsh, or fish)", other);
+            exit(1);
+        }
+    }
+}
+
+fn run_install(with_deps: bool, engine: &str, show_spinner: bool) {
     let is_linux = cfg!(target_os = "linux");
     
     if is_linux {
         if with_deps {
-            println!("\x1b[36mInstalling system dependencies...\x1b[0m");
-            
-            // Detect package manager and install deps
-            let (pkg_mgr, deps) = if which_exists("apt-get") {
-                ("apt-get", vec![
-                    "libxcb-shm0", "libx11-xcb1", "libx11-6", "libxcb1", "libxext6",
-                    "libxrandr2", "libxcomposite1", "libxcursor1", "libxdamage1", "libxfixes3",
-                    "libxi6", "libgtk-3-0", "libpangocairo-1.0-0", "libpango-1.0-0", "libatk1.0-0",
-                    "libcairo-gobject2", "libcairo2", "libgdk-pixbuf-2.0-0", "libxrender1",
-                    "libasound2", "libfreetype6", "libfontconfig1", "libdbus-1-3", "libnss3",
-                    "libnspr4", "libatk-bridge2.0-0", "libdrm2", "libxkbcommon0", "libatspi2.0-0",
-                    "libcups2", "libxshmfence1", "libgbm1",
-                ])
-            } else if which_exists("dnf") {
-                ("dnf", vec![
-                    "nss", "nspr", "atk", "at-spi2-atk", "cups-libs", "libdrm",
-                    "libXcomposite", "libXdamage", "libXrandr", "mesa-libgbm", "pango",
-                    "alsa-lib", "libxkbcommon", "libxcb", "libX11-xcb", "libX11", "libXext",
-                    "libXcursor", "libXfixes", "libXi", "gtk3", "cairo-gobject",
-                ])
-            } else if which_exists("yum") {
-                ("yum", vec![
-                    "nss", "nspr", "atk", "at-spi2-atk", "cups-libs", "libdrm",
-                    "libXcomposite", "libXdamage", "libXrandr", "mesa-libgbm", "pango",
-                    "alsa-lib", "libxkbcommon",
-                ])
-            } else {
-                eprintln!("\x1b[31m✗\x1b[0m No supported package manager found (apt-get, dnf, or yum)");
-                exit(1);
-            };
-            
-            let install_cmd = match pkg_mgr {
-                "apt-get" => format!("sudo apt-get update && sudo apt-get install -y {}", deps.join(" ")),
-                _ => format!("sudo {} install -y {}", pkg_mgr, deps.join(" ")),
-            };
-            
-            println!("Running: {}", install_cmd);
-            let status = Command::new("sh")
-                .arg("-c")
-                .arg(&install_cmd)
+            // Delegate to Playwright so the libraries for the chosen engine are
+            // installed — WebKit and Firefox pull a different system package set
+            // than Chromium. This shells out to the distro package manager via
+            // `sudo`, so keep stdio inherited (no spinner): the password prompt
+            // must stay visible and stderr must report the real failure cause.
+            println!("\x1b[36mInstalling system dependencies for {}...\x1b[0m", engine);
+            let status = Command::new("npx")
+                .args(["playwright", "install-deps", engine])
                 .status();
-            
+
             match status {
                 Ok(s) if s.success() => println!("\x1b[32m✓\x1b[0m System dependencies installed"),
                 Ok(_) => {
@@ -348,20 +635,26 @@ fn run_install(with_deps: bool) {
         } else {
             println!("\x1b[33m⚠\x1b[0m Linux detected. If browser fails to launch, run:");
             println!("  agent-browser install --with-deps");
-            println!("  or: npx playwright install-deps chromium");
+            println!("  or: npx playwright install-deps {}", engine);
             println!();
         }
     }
     
     // Install browser binaries
-    println!("\x1b[36mInstalling Chromium browser...\x1b[0m");
-    let status = Command::new("npx")
-        .args(["playwright", "install", "chromium"])
-        .status();
-    
+    if !show_spinner {
+        println!("\x1b[36mInstalling {} browser...\x1b[0m", engine);
+    }
+    let mut install = Command::new("npx");
+    install.args(["playwright", "install", engine]);
+    let status = run_with_spinner(
+        &format!("Installing {} browser", engine),
+        show_spinner,
+        &mut install,
+    );
+
     match status {
         Ok(s) if s.success() => {
-            println!("\x1b[32m✓\x1b[0m Chromium installed successfully");
+            println!("\x1b[32m✓\x1b[0m {} installed successfully", engine);
             if is_linux && !with_deps {
                 println!();
                 println!("\x1b[33mNote:\x1b[0m If you see \"shared library\" errors when running, use:");
@@ -384,30 +677,58 @@ fn run_install(with_deps: bool) {
     }
 }
 
-fn which_exists(cmd: &str) -> bool {
-    Command::new("which")
-        .arg(cmd)
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .status()
-        .map(|s| s.success())
-        .unwrap_or(false)
-}
-
 fn main() {
-    let args: Vec<String> = env::args().skip(1).collect();
-    let json_mode = args.iter().any(|a| a == "--json");
+    let raw_args: Vec<String> = env::args().skip(1).collect();
+    let json_mode = raw_args.iter().any(|a| a == "--json");
+
+    // Pull the `--browser <engine>` value out before filtering flags so the
+    // engine name isn't mistaken for a command.
+    let mut args: Vec<String> = Vec::with_capacity(raw_args.len());
+    let mut iter = raw_args.iter();
+    while let Some(a) = iter.next() {
+        if a == "--browser" {
+            if let Some(engine) = iter.next() {
+                if !matches!(engine.as_str(), "chromium" | "firefox" | "webkit") {
+                    eprintln!(
+                        "\x1b[31m✗\x1b[0m Invalid --browser engine: {} (expected chromium, firefox, or webkit)",
+                        engine
+                    );
+                    exit(1);
+                }
+                env::set_var("AGENT_BROWSER_ENGINE", engine);
+            }
+        } else if a == "--remote" {
+            if let Some(addr) = iter.next() {
+                env::set_var("AGENT_BROWSER_REMOTE", addr);
+            }
+        } else {
+            args.push(a.clone());
+        }
+    }
+
     let clean_args: Vec<String> = args.iter().filter(|a| !a.starts_with("--")).cloned().collect();
-    
+
     if clean_args.is_empty() || args.iter().any(|a| a == "--help" || a == "-h") {
         print_help();
         return;
     }
-    
+
     // Handle install command separately (doesn't need daemon)
     if clean_args.get(0).map(|s| s.as_str()) == Some("install") {
         let with_deps = args.iter().any(|a| a == "--with-deps" || a == "-d");
-        run_install(with_deps);
+        run_install(with_deps, &get_engine(), spinner_enabled(json_mode));
+        return;
+    }
+
+    // Completions print to stdout and never touch the daemon.
+    if clean_args.get(0).map(|s| s.as_str()) == Some("completions") {
+        match clean_args.get(1).map(|s| s.as_str()) {
+            Some(shell) => run_completions(shell),
+            None => {
+                eprintln!("\x1b[31m✗\x1b[0m Usage: agent-browser completions <bash|zsh|fish>");
+                exit(1);
+            }
+        }
         return;
     }
     
@@ -419,13 +740,16 @@ fn main() {
         }
     };
     
-    if let Err(e) = ensure_daemon() {
-        if json_mode {
-            println!(r#"{{"success":false,"error":"{}"}}"#, e);
-        } else {
-            eprintln!("\x1b[31m✗ Error:\x1b[0m {}", e);
+    // A remote daemon is already running on another host; skip local startup.
+    if env::var("AGENT_BROWSER_REMOTE").is_err() {
+        if let Err(e) = ensure_daemon(spinner_enabled(json_mode)) {
+            if json_mode {
+                println!(r#"{{"success":false,"error":"{}"}}"#, e);
+            } else {
+                eprintln!("\x1b[31m✗ Error:\x1b[0m {}", e);
+            }
+            exit(1);
         }
-        exit(1);
     }
     
     match send_command(cmd) {